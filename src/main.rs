@@ -2,14 +2,35 @@ slint::include_modules!();
 use slint::{ModelRc, StandardListViewItem, VecModel};
 
 use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use get_if_addrs::{get_if_addrs, IfAddr};
-use sysinfo::{Networks, System};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use sysinfo::{Components, Disks, Networks, System};
 
 const GIB: f32 = 1024.0 * 1024.0 * 1024.0;
 
+/// Binary byte-unit ladder, smallest first.
+const BYTE_UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+
+/// Scale a raw byte count down the binary ladder (dividing by 1024) until the
+/// value sits in the 1–1024 range, returning the scaled value (rounded to one
+/// decimal to match the `{:.1}` display format) and its unit label, so a 3 GiB
+/// transfer reads `3.0 GiB`.
+fn get_simple_byte_values(bytes: u64) -> (f32, &'static str) {
+    let mut value = bytes as f32;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < BYTE_UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    ((value * 10.0).round() / 10.0, BYTE_UNITS[unit])
+}
+
 // ---------------- DATA TYPES ----------------
 
 #[derive(Clone, Default, Copy)]
@@ -18,58 +39,230 @@ struct NetworkHistoryPoint {
     download: f32,
 }
 
+/// Drop every `(Instant, _)` sample at the front of `buf` that is older than
+/// `window`. Samples are appended in time order, so eviction stops at the first
+/// still-fresh entry.
+fn prune_stale<T>(buf: &mut VecDeque<(Instant, T)>, now: Instant, window: Duration) {
+    while let Some((t, _)) = buf.front() {
+        if now.duration_since(*t) > window {
+            buf.pop_front();
+        } else {
+            break;
+        }
+    }
+}
+
+/// Rolling history of CPU/memory/network samples. Each series is a `VecDeque`
+/// of `(Instant, value)` pairs so pushes are amortized O(1) and retention is a
+/// duration-based policy: samples older than `window` are evicted on every
+/// push, which lets a wider or narrower view change the window without
+/// resizing any buffers.
 struct SystemHistory {
-    cpu_history: Vec<f32>,
-    memory_history: Vec<f32>,
-    net_history: Vec<NetworkHistoryPoint>,
+    cpu_history: VecDeque<(Instant, f32)>,
+    memory_history: VecDeque<(Instant, f32)>,
+    net_history: VecDeque<(Instant, NetworkHistoryPoint)>,
+    window: Duration,
     last_rx: u64,
     last_tx: u64,
 }
 
 impl SystemHistory {
-    fn new(size: usize) -> Self {
+    fn new(window: Duration) -> Self {
         Self {
-            cpu_history: vec![0.0; size],
-            memory_history: vec![0.0; size],
-            net_history: vec![NetworkHistoryPoint::default(); size],
+            cpu_history: VecDeque::new(),
+            memory_history: VecDeque::new(),
+            net_history: VecDeque::new(),
+            window,
             last_rx: 0,
             last_tx: 0,
         }
     }
 
     fn push_cpu(&mut self, v: f32) {
-        self.cpu_history.remove(0);
-        self.cpu_history.push(v);
+        let now = Instant::now();
+        self.cpu_history.push_back((now, v));
+        prune_stale(&mut self.cpu_history, now, self.window);
     }
 
     fn push_mem(&mut self, v: f32) {
-        self.memory_history.remove(0);
-        self.memory_history.push(v);
+        let now = Instant::now();
+        self.memory_history.push_back((now, v));
+        prune_stale(&mut self.memory_history, now, self.window);
     }
 
     fn push_net(&mut self, up: f32, down: f32) {
-        self.net_history.remove(0);
-        self.net_history.push(NetworkHistoryPoint {
-            upload: up,
-            download: down,
-        });
+        let now = Instant::now();
+        self.net_history.push_back((
+            now,
+            NetworkHistoryPoint {
+                upload: up,
+                download: down,
+            },
+        ));
+        prune_stale(&mut self.net_history, now, self.window);
+    }
+
+    /// Retained CPU samples as a bare value slice for the chart models.
+    fn cpu_values(&self) -> Vec<f32> {
+        self.cpu_history.iter().map(|(_, v)| *v).collect()
+    }
+
+    /// Retained memory samples as a bare value slice for the chart models.
+    fn memory_values(&self) -> Vec<f32> {
+        self.memory_history.iter().map(|(_, v)| *v).collect()
+    }
+
+    /// Retained network samples as a bare value slice for the chart models.
+    fn net_values(&self) -> Vec<NetworkHistoryPoint> {
+        self.net_history.iter().map(|(_, v)| *v).collect()
+    }
+}
+
+/// Process-table filter state. The compiled `Regex` is cached so we only pay
+/// the compilation cost when the query text actually changes or when the user
+/// toggles back into regex mode — not on every 1-second refresh, and never
+/// while simple substring matching is active.
+struct ProcessFilter {
+    query: String,
+    use_regex: bool,
+    compiled: Option<Regex>,
+}
+
+impl ProcessFilter {
+    fn new(use_regex: bool) -> Self {
+        Self {
+            query: String::new(),
+            use_regex,
+            compiled: None,
+        }
+    }
+
+    /// Refresh the cached pattern for the current query/mode, recompiling only
+    /// when necessary.
+    fn sync(&mut self, query: &str, use_regex: bool) {
+        let entering_regex = use_regex && !self.use_regex;
+        if use_regex && (entering_regex || query != self.query) {
+            self.compiled = Regex::new(query).ok();
+        }
+        self.query = query.to_string();
+        self.use_regex = use_regex;
+    }
+
+    /// Whether a process name passes the current filter. An empty query or a
+    /// regex that failed to compile both match everything.
+    fn matches(&self, name: &str) -> bool {
+        if self.query.is_empty() {
+            return true;
+        }
+        if self.use_regex {
+            match &self.compiled {
+                Some(re) => re.is_match(name),
+                None => true,
+            }
+        } else {
+            name.to_lowercase().contains(&self.query.to_lowercase())
+        }
+    }
+}
+
+// ---------------- CONFIG ----------------
+
+/// User-tunable settings loaded from a TOML file. A lightweight background poll
+/// might set `refresh_interval_ms = 5000`, while diagnosing a spike wants
+/// `refresh_interval_ms = 250`. Unspecified keys fall back to the defaults
+/// below, and a missing file is created with those defaults.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+struct Config {
+    /// Timer tick in milliseconds (how often every subsystem is refreshed).
+    refresh_interval_ms: u64,
+    /// How many seconds of samples the history ring buffers retain.
+    history_window_secs: u64,
+    /// Default column the process table sorts by (e.g. "cpu", "memory", "pid").
+    process_sort: String,
+    /// Whether the process table starts in group-by-name mode.
+    group_by_name: bool,
+    /// Whether the process filter starts in regex mode.
+    use_regex: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            refresh_interval_ms: 1000,
+            history_window_secs: 60,
+            process_sort: "cpu".to_string(),
+            group_by_name: false,
+            use_regex: false,
+        }
+    }
+}
+
+/// Resolve the config path from `--config <path>` / `-C <path>` (or
+/// `--config=<path>`), defaulting to `sysinfo.toml` in the working directory.
+fn config_path_from_args() -> PathBuf {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--config" || arg == "-C" {
+            if let Some(path) = args.next() {
+                return PathBuf::from(path);
+            }
+        } else if let Some(path) = arg.strip_prefix("--config=") {
+            return PathBuf::from(path);
+        }
+    }
+    PathBuf::from("sysinfo.toml")
+}
+
+/// Load the config at `path`, falling back to defaults on a parse error and
+/// writing a default file when none exists yet.
+fn load_config(path: &Path) -> Config {
+    match std::fs::read_to_string(path) {
+        Ok(contents) => toml::from_str(&contents).unwrap_or_default(),
+        Err(_) => {
+            let config = Config::default();
+            if let Ok(contents) = toml::to_string_pretty(&config) {
+                let _ = std::fs::write(path, contents);
+            }
+            config
+        }
     }
 }
 
 // ---------------- MAIN ----------------
 
 fn main() -> Result<(), slint::PlatformError> {
+    let config = load_config(&config_path_from_args());
+
     let ui = AppWindow::new()?;
     let ui_handle = ui.as_weak();
 
+    // Seed the process view with the configured default modes.
+    ui.set_process_use_regex(config.use_regex);
+    ui.set_process_group_by_name(config.group_by_name);
+
     let mut sys = System::new_all();
     let mut networks = Networks::new_with_refreshed_list();
-    let history = Rc::new(RefCell::new(SystemHistory::new(60)));
+    let mut disks = Disks::new_with_refreshed_list();
+    let mut components = Components::new_with_refreshed_list();
+    let history = Rc::new(RefCell::new(SystemHistory::new(Duration::from_secs(
+        config.history_window_secs,
+    ))));
+    let proc_filter = Rc::new(RefCell::new(ProcessFilter::new(config.use_regex)));
+
+    // Default column the process/grouped rows sort by, threaded into the gather.
+    let process_sort = config.process_sort.clone();
+
+    // Clamp to a 100 ms floor so a zero/typo interval can't turn the repeated
+    // timer into a busy loop that pegs the CPU.
+    let refresh_interval = Duration::from_millis(config.refresh_interval_ms.max(100));
+    // Used to convert per-tick byte deltas into a per-second network rate.
+    let interval_secs = refresh_interval.as_secs_f32();
 
     let timer = slint::Timer::default();
     timer.start(
         slint::TimerMode::Repeated,
-        Duration::from_secs(1),
+        refresh_interval,
         move || {
             let ui = match ui_handle.upgrade() {
                 Some(u) => u,
@@ -81,6 +274,8 @@ fn main() -> Result<(), slint::PlatformError> {
             sys.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
 
             networks.refresh(false);
+            disks.refresh(false);
+            components.refresh(false);
 
             let mut h = history.borrow_mut();
 
@@ -90,9 +285,14 @@ fn main() -> Result<(), slint::PlatformError> {
             h.push_cpu(cpu);
             h.push_mem(mem);
 
-            ui.set_homeData(gather_home_data(&sys, &h.cpu_history));
-            ui.set_cpu_data(gather_cpu_data(&sys, &h.cpu_history));
-            ui.set_memory_data(gather_memory_data(&sys, &h.memory_history));
+            let disk_used = aggregate_disk_usage(&disks);
+
+            let cpu_values = h.cpu_values();
+            let memory_values = h.memory_values();
+
+            ui.set_homeData(gather_home_data(&sys, &cpu_values, disk_used));
+            ui.set_cpu_data(gather_cpu_data(&sys, &cpu_values, &components));
+            ui.set_memory_data(gather_memory_data(&sys, &memory_values));
 
             let is_first_run = h.last_rx == 0 && h.last_tx == 0;
 
@@ -104,8 +304,11 @@ fn main() -> Result<(), slint::PlatformError> {
                 total_tx += data.total_transmitted();
             }
 
-            let down = (total_rx.saturating_sub(h.last_rx) as f32 / 1024.0).max(0.0);
-            let up = (total_tx.saturating_sub(h.last_tx) as f32 / 1024.0).max(0.0);
+            // Per-tick byte deltas scaled by the tick length into a bytes-per-
+            // second rate, so the reported speed is correct at any configured
+            // refresh interval.
+            let down = (total_rx.saturating_sub(h.last_rx) as f32 / interval_secs).max(0.0);
+            let up = (total_tx.saturating_sub(h.last_tx) as f32 / interval_secs).max(0.0);
 
             h.last_rx = total_rx;
             h.last_tx = total_tx;
@@ -114,14 +317,30 @@ fn main() -> Result<(), slint::PlatformError> {
                 h.push_net(up, down);
             }
 
+            let net_values = h.net_values();
             ui.set_network_data(gather_network_data(
                 &networks,
-                &h.net_history,
+                &net_values,
                 total_tx,
                 total_rx,
             ));
 
-            let table_data: Vec<Vec<StandardListViewItem>> = gather_process_table_data(&sys);
+            // Disk table (per-mount usage), same row-model shape as the process table
+            let disk_rows: Vec<ModelRc<StandardListViewItem>> = gather_disk_data(&disks)
+                .into_iter()
+                .map(|row| ModelRc::from(Rc::new(VecModel::from(row))))
+                .collect();
+            ui.set_disk_data(ModelRc::from(Rc::new(VecModel::from(disk_rows))));
+
+            let mut filter = proc_filter.borrow_mut();
+            filter.sync(ui.get_process_query().as_str(), ui.get_process_use_regex());
+            let table_data: Vec<Vec<StandardListViewItem>> =
+                gather_process_table_data(
+                    &sys,
+                    &filter,
+                    ui.get_process_group_by_name(),
+                    &process_sort,
+                );
 
             // 1. Map rows into ModelRc<StandardListViewItem>
             let row_models: Vec<ModelRc<StandardListViewItem>> = table_data
@@ -146,7 +365,7 @@ fn main() -> Result<(), slint::PlatformError> {
 
 // ---------------- GATHER FUNCTIONS ----------------
 
-fn gather_home_data(sys: &System, cpu_hist: &[f32]) -> Home_Full_Data {
+fn gather_home_data(sys: &System, cpu_hist: &[f32], disk_used: f32) -> Home_Full_Data {
     let total_mem = sys.total_memory() as f32;
     let used_mem = sys.used_memory() as f32;
     Home_Full_Data {
@@ -164,7 +383,7 @@ fn gather_home_data(sys: &System, cpu_hist: &[f32]) -> Home_Full_Data {
             disk: Home_LineGraph_Data {
                 lower_val: 0.0,
                 upper_val: 100.0,
-                curr_val: 0.0,
+                curr_val: disk_used,
             },
             network: Home_LineGraph_Data {
                 lower_val: 0.0,
@@ -196,15 +415,16 @@ fn gather_home_data(sys: &System, cpu_hist: &[f32]) -> Home_Full_Data {
     }
 }
 
-fn gather_cpu_data(sys: &System, cpu_hist: &[f32]) -> Cpu_Full_Data {
+fn gather_cpu_data(sys: &System, cpu_hist: &[f32], components: &Components) -> Cpu_Full_Data {
     let cpus = sys.cpus();
     let core_usages: Vec<f32> = cpus.iter().map(|cpu| cpu.cpu_usage()).collect();
     let y_max = cpu_hist.iter().copied().fold(0.0, f32::max).max(1.0);
+    let core_temp = hottest_cpu_temp(components);
     Cpu_Full_Data {
         total_consumption: sys.global_cpu_usage(),
         cpu_info: Cpu_Info_Data {
             clock_speed: format!("{} MHz", cpus.first().map(|c| c.frequency()).unwrap_or(0)).into(),
-            core_temp: "N/A".into(),
+            core_temp: core_temp.into(),
             avg_Load: format!("{:.2}", System::load_average().one).into(),
             freq: format!("{} MHz", cpus.first().map(|c| c.frequency()).unwrap_or(0)).into(),
             freq_base: "N/A".into(),
@@ -231,6 +451,28 @@ fn gather_cpu_data(sys: &System, cpu_hist: &[f32]) -> Cpu_Full_Data {
     }
 }
 
+/// Hottest CPU-related sensor, formatted as `"{:.0}°C"`. Picks the component
+/// whose label looks like a CPU sensor ("Core", "Package", or "cpu") and
+/// reports the maximum current temperature, falling back to "N/A" when no such
+/// sensor is exposed (common on laptops and in VMs).
+fn hottest_cpu_temp(components: &Components) -> String {
+    let hottest = components
+        .list()
+        .iter()
+        .filter(|c| {
+            let label = c.label().to_ascii_lowercase();
+            label.contains("core") || label.contains("package") || label.contains("cpu")
+        })
+        .filter_map(|c| c.temperature())
+        .fold(f32::NEG_INFINITY, f32::max);
+
+    if hottest.is_finite() {
+        format!("{:.0}°C", hottest)
+    } else {
+        "N/A".to_string()
+    }
+}
+
 fn gather_memory_data(sys: &System, mem_hist: &[f32]) -> Memory_Full_Data {
     let total = sys.total_memory() as f32;
     let used = sys.used_memory() as f32;
@@ -327,15 +569,24 @@ fn gather_network_data(
             ipv6: ipv6.into(),
             mac: data.mac_address().to_string().into(),
             // Optional: Show total data per specific interface
-            sent: format!("{:.2} MB", data.total_transmitted() as f32 / 1048576.0).into(),
-            received: format!("{:.2} MB", data.total_received() as f32 / 1048576.0).into(),
+            sent: {
+                let (v, u) = get_simple_byte_values(data.total_transmitted());
+                format!("{:.1} {}", v, u).into()
+            },
+            received: {
+                let (v, u) = get_simple_byte_values(data.total_received());
+                format!("{:.1} {}", v, u).into()
+            },
         });
     }
 
     Network_Full_Data {
+        // `current_speed` feeds unit-less f32 fields whose UI labels are a fixed
+        // "KiB/s" suffix, so scale the raw bytes-per-second deltas by a single
+        // /1024 step rather than adaptively (which would mislabel the number).
         current_speed: Network_Speed_Data {
-            upload: last_point.upload,
-            download: last_point.download,
+            upload: last_point.upload / 1024.0,
+            download: last_point.download / 1024.0,
         },
         usage: Network_Usage_Data {
             upload: Network_Chart_Data {
@@ -354,8 +605,10 @@ fn gather_network_data(
             mac: mac.into(),
         },
         active_stat: Network_Active_Stat_Data {
-            total_sent: (total_tx as f32 * 100.0).round() / 100.0,
-            total_received: (total_rx as f32 * 100.0).round() / 100.0,
+            // Unit-less f32 fields with a fixed "MiB" label in the UI; scale to
+            // mebibytes instead of adaptively so the number matches the suffix.
+            total_sent: total_tx as f32 / 1048576.0,
+            total_received: total_rx as f32 / 1048576.0,
             interfaces: networks.len() as f32,
             link_status: "Active".into(),
         },
@@ -363,9 +616,84 @@ fn gather_network_data(
     }
 }
 
-fn gather_process_table_data(sys: &sysinfo::System) -> Vec<Vec<slint::StandardListViewItem>> {
-    sys.processes()
+/// Aggregate used percentage across every mounted partition, weighting each
+/// volume by its size so a nearly-full small partition doesn't skew the total.
+fn aggregate_disk_usage(disks: &Disks) -> f32 {
+    let mut total = 0u64;
+    let mut used = 0u64;
+    for disk in disks.list() {
+        let t = disk.total_space();
+        total += t;
+        used += t.saturating_sub(disk.available_space());
+    }
+    if total > 0 {
+        (used as f32 / total as f32) * 100.0
+    } else {
+        0.0
+    }
+}
+
+/// Per-mount disk usage rows: mount point, total, available, used %, and
+/// filesystem type. Mirrors the process table's row-of-cells layout so the UI
+/// can render a per-volume table with the same widget.
+fn gather_disk_data(disks: &Disks) -> Vec<Vec<slint::StandardListViewItem>> {
+    disks
+        .list()
+        .iter()
+        .map(|disk| {
+            let total = disk.total_space();
+            let available = disk.available_space();
+            let used_pct = if total > 0 {
+                (total.saturating_sub(available) as f32 / total as f32) * 100.0
+            } else {
+                0.0
+            };
+            vec![
+                slint::StandardListViewItem::from(disk.mount_point().to_string_lossy().as_ref()),
+                slint::StandardListViewItem::from(format!("{:.1} GB", total as f32 / 1e9).as_str()),
+                slint::StandardListViewItem::from(
+                    format!("{:.1} GB", available as f32 / 1e9).as_str(),
+                ),
+                slint::StandardListViewItem::from(format!("{:.1}%", used_pct).as_str()),
+                slint::StandardListViewItem::from(disk.file_system().to_string_lossy().as_ref()),
+            ]
+        })
+        .collect()
+}
+
+fn gather_process_table_data(
+    sys: &sysinfo::System,
+    filter: &ProcessFilter,
+    group_by_name: bool,
+    sort: &str,
+) -> Vec<Vec<slint::StandardListViewItem>> {
+    use std::cmp::Ordering;
+
+    if group_by_name {
+        return gather_grouped_process_table_data(sys, filter, sort);
+    }
+    let mut procs: Vec<_> = sys
+        .processes()
         .iter()
+        .filter(|(_, proc)| filter.matches(&proc.name().to_string_lossy()))
+        .collect();
+    match sort {
+        "memory" => procs.sort_by(|a, b| b.1.memory().cmp(&a.1.memory())),
+        "pid" => procs.sort_by(|a, b| a.0.as_u32().cmp(&b.0.as_u32())),
+        "name" => procs.sort_by(|a, b| {
+            a.1.name()
+                .to_string_lossy()
+                .to_lowercase()
+                .cmp(&b.1.name().to_string_lossy().to_lowercase())
+        }),
+        _ => procs.sort_by(|a, b| {
+            b.1.cpu_usage()
+                .partial_cmp(&a.1.cpu_usage())
+                .unwrap_or(Ordering::Equal)
+        }),
+    }
+    procs
+        .into_iter()
         .map(|(pid, proc)| {
             // Each row is a Vec of StandardListViewItem
             vec![
@@ -379,3 +707,67 @@ fn gather_process_table_data(sys: &sysinfo::System) -> Vec<Vec<slint::StandardLi
         })
         .collect()
 }
+
+/// Collapse every process sharing a name into one row, summing CPU and memory
+/// and collecting the group's PIDs (like bottom's `group_pids`). The PID column
+/// reports the process count followed by the grouped PIDs so the table stays the
+/// same four-column shape as the ungrouped view.
+fn gather_grouped_process_table_data(
+    sys: &sysinfo::System,
+    filter: &ProcessFilter,
+    sort: &str,
+) -> Vec<Vec<slint::StandardListViewItem>> {
+    use std::cmp::Ordering;
+    use std::collections::HashMap;
+
+    struct Group {
+        cpu: f32,
+        memory: u64,
+        pids: Vec<u32>,
+    }
+
+    let mut groups: HashMap<String, Group> = HashMap::new();
+    for (pid, proc) in sys.processes() {
+        let name = proc.name().to_string_lossy().to_string();
+        if !filter.matches(&name) {
+            continue;
+        }
+        let group = groups.entry(name).or_insert(Group {
+            cpu: 0.0,
+            memory: 0,
+            pids: Vec::new(),
+        });
+        group.cpu += proc.cpu_usage();
+        group.memory += proc.memory();
+        group.pids.push(pid.as_u32());
+    }
+
+    let mut rows: Vec<(String, Group)> = groups.into_iter().collect();
+    match sort {
+        "memory" => rows.sort_by(|a, b| b.1.memory.cmp(&a.1.memory)),
+        "pid" => rows.sort_by(|a, b| a.1.pids.iter().min().cmp(&b.1.pids.iter().min())),
+        "name" => rows.sort_by(|a, b| a.0.to_lowercase().cmp(&b.0.to_lowercase())),
+        _ => rows.sort_by(|a, b| b.1.cpu.partial_cmp(&a.1.cpu).unwrap_or(Ordering::Equal)),
+    }
+
+    rows.into_iter()
+        .map(|(name, group)| {
+            let pids = group
+                .pids
+                .iter()
+                .map(|p| p.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            vec![
+                slint::StandardListViewItem::from(
+                    format!("{} ({})", group.pids.len(), pids).as_str(),
+                ),
+                slint::StandardListViewItem::from(name.as_str()),
+                slint::StandardListViewItem::from(format!("{:.1}%", group.cpu).as_str()),
+                slint::StandardListViewItem::from(
+                    format!("{:.1} MB", group.memory as f32 / 1024.0 / 1024.0).as_str(),
+                ),
+            ]
+        })
+        .collect()
+}